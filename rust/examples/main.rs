@@ -7,50 +7,58 @@ fn main() {
         PricingNode {
             path: "/material".to_string(),
             node_type: "label".to_string(),
-            cost: 100.0,
+            cost: Value::from_f64(100.0),
             value: Some(json!("tpa")),
+            currency: None,
         },
         PricingNode {
             path: "/material".to_string(),
             node_type: "label".to_string(),
-            cost: 20.0,
+            cost: Value::from_f64(20.0),
             value: Some(json!("pla")),
+            currency: None,
         },
         PricingNode {
             path: "/material".to_string(),
             node_type: "label".to_string(),
-            cost: 0.0,
+            cost: Value::from_f64(0.0),
             value: Some(json!("resin")),
+            currency: None,
         },
         PricingNode {
             path: "/material/resin/color".to_string(),
             node_type: "label".to_string(),
-            cost: 30.0,
+            cost: Value::from_f64(30.0),
             value: Some(json!("red")),
+            currency: None,
         },
         PricingNode {
             path: "/material/resin/color".to_string(),
             node_type: "label".to_string(),
-            cost: 30.0,
+            cost: Value::from_f64(30.0),
             value: Some(json!("blue")),
+            currency: None,
         },
         PricingNode {
             path: "/material/pla/color".to_string(),
             node_type: "label".to_string(),
-            cost: 300.0,
+            cost: Value::from_f64(300.0),
             value: Some(json!("blue")),
+            currency: None,
         },
         PricingNode {
             path: "/volume".to_string(),
             node_type: "numeric".to_string(),
-            cost: 10.0,
+            cost: Value::from_f64(10.0),
             value: None,
+            currency: None,
         },
         PricingNode {
             path: "/time_taken".to_string(),
             node_type: "numeric".to_string(),
-            cost: 100.0,
+            cost: Value::from_f64(100.0),
             value: None,
+            currency: None,
         },
     ];
 
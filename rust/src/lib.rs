@@ -2,9 +2,351 @@ use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-/// Value type used throughout the pricing engine
+/// Value type used throughout the pricing engine.
+///
+/// Defaults to `f64`. Enable the `decimal` feature to switch the engine to a
+/// base-10 fixed-point backend (`rust_decimal::Decimal`) so that percentages,
+/// rounding, and comparisons on money amounts are computed exactly instead of
+/// accumulating binary floating-point rounding error.
+#[cfg(not(feature = "decimal"))]
 pub type Value = f64;
 
+/// Value type used throughout the pricing engine (decimal backend).
+#[cfg(feature = "decimal")]
+pub type Value = rust_decimal::Decimal;
+
+/// Numeric operations that differ between the `f64` and `decimal` backends.
+///
+/// Arithmetic (`+`, `-`, `*`, `/`, comparisons) behaves identically on both
+/// backends via their `std::ops`/`PartialOrd` impls, so `process_*` methods
+/// use plain operators wherever possible. This trait covers the places where
+/// the two backends diverge: constructing a `Value` from a literal, identity
+/// elements, powers of ten, display formatting that preserves the backend's
+/// native scale, rounding, and the functions behind the math steps.
+pub trait NumericOps: Sized {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn from_f64(v: f64) -> Self;
+    /// Parse a JSON number, preferring its exact textual form over an `f64`
+    /// round-trip so the decimal backend doesn't inherit binary-float drift
+    /// (e.g. `0.1` stays `0.1`, not `0.1000000000000000055511151231257827`).
+    fn from_json_number(n: &serde_json::Number) -> Self;
+    fn pow10(decimals: i32) -> Self;
+    fn display(&self) -> String;
+    /// Round to `decimals` places using the given [`RoundingStrategy`].
+    fn round_with_strategy(self, decimals: i32, strategy: RoundingStrategy) -> Self;
+    /// Truncate to an `i32`, used for decimal-place / exponent arguments.
+    fn to_i32(self) -> i32;
+    /// Largest integer less than or equal to `self`.
+    fn floor_value(self) -> Self;
+    /// Integer part of `self`, discarding any fractional component.
+    fn trunc_value(self) -> Self;
+    /// Whether this value represents positive/negative infinity. The decimal
+    /// backend has no such representation and always returns `false`.
+    fn is_infinite_value(&self) -> bool;
+    /// Whether this value is neither infinite nor `NaN`. The decimal backend
+    /// has no representation for either and always returns `true`.
+    fn is_finite_value(&self) -> bool;
+    /// Smallest integer greater than or equal to `self`.
+    fn ceil_value(self) -> Self;
+    /// Absolute value.
+    fn abs_value(self) -> Self;
+    /// `-1`, `0`, or `1` depending on the sign of `self`.
+    fn sign_value(self) -> Self;
+    /// `self` raised to `exponent`.
+    fn powf_value(self, exponent: Self) -> Self;
+    /// Square root, or `None` if `self` is negative.
+    fn sqrt_value(self) -> Option<Self>;
+    /// Cube root.
+    fn cbrt_value(self) -> Self;
+    /// Natural log, or `None` if `self` is not strictly positive.
+    fn ln_value(self) -> Option<Self>;
+}
+
+/// Rounding rule applied by the `round` step.
+///
+/// `MidpointAwayFromZero` (round-half-up) is the historical, and default,
+/// behavior. The others mirror the rounding modes found in decimal/money
+/// libraries for jurisdictions or accounting policies that require a
+/// different tie-breaking or directional rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingStrategy {
+    /// Round halves to the nearest even neighbor (banker's rounding).
+    MidpointNearestEven,
+    /// Round halves away from zero. Default.
+    #[default]
+    MidpointAwayFromZero,
+    /// Truncate toward zero.
+    ToZero,
+    /// Round away from zero.
+    AwayFromZero,
+    /// Round toward negative infinity (floor).
+    ToNegativeInfinity,
+    /// Round toward positive infinity (ceiling).
+    ToPositiveInfinity,
+}
+
+impl RoundingStrategy {
+    /// Human-readable name recorded in `BreakdownEntry.operation`/`description`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            RoundingStrategy::MidpointNearestEven => "banker's rounding (midpoint to even)",
+            RoundingStrategy::MidpointAwayFromZero => "midpoint away from zero",
+            RoundingStrategy::ToZero => "truncate toward zero",
+            RoundingStrategy::AwayFromZero => "away from zero",
+            RoundingStrategy::ToNegativeInfinity => "toward negative infinity (floor)",
+            RoundingStrategy::ToPositiveInfinity => "toward positive infinity (ceiling)",
+        }
+    }
+}
+
+#[cfg(not(feature = "decimal"))]
+impl NumericOps for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn from_f64(v: f64) -> Self {
+        v
+    }
+
+    fn from_json_number(n: &serde_json::Number) -> Self {
+        n.as_f64().unwrap_or(0.0)
+    }
+
+    fn pow10(decimals: i32) -> Self {
+        10_f64.powi(decimals)
+    }
+
+    fn display(&self) -> String {
+        format!("{:.2}", self)
+    }
+
+    fn round_with_strategy(self, decimals: i32, strategy: RoundingStrategy) -> Self {
+        let multiplier = 10_f64.powi(decimals);
+        let scaled = self * multiplier;
+        let rounded = match strategy {
+            RoundingStrategy::MidpointAwayFromZero => scaled.round(),
+            RoundingStrategy::MidpointNearestEven => {
+                let floor = scaled.floor();
+                if (scaled - floor - 0.5).abs() < 1e-9 {
+                    if (floor as i64) % 2 == 0 {
+                        floor
+                    } else {
+                        floor + 1.0
+                    }
+                } else {
+                    scaled.round()
+                }
+            }
+            RoundingStrategy::ToZero => scaled.trunc(),
+            RoundingStrategy::AwayFromZero => {
+                if scaled >= 0.0 {
+                    scaled.ceil()
+                } else {
+                    scaled.floor()
+                }
+            }
+            RoundingStrategy::ToNegativeInfinity => scaled.floor(),
+            RoundingStrategy::ToPositiveInfinity => scaled.ceil(),
+        };
+        rounded / multiplier
+    }
+
+    fn to_i32(self) -> i32 {
+        self as i32
+    }
+
+    fn floor_value(self) -> Self {
+        self.floor()
+    }
+
+    fn trunc_value(self) -> Self {
+        self.trunc()
+    }
+
+    fn is_infinite_value(&self) -> bool {
+        f64::is_infinite(*self)
+    }
+
+    fn is_finite_value(&self) -> bool {
+        f64::is_finite(*self)
+    }
+
+    fn ceil_value(self) -> Self {
+        self.ceil()
+    }
+
+    fn abs_value(self) -> Self {
+        self.abs()
+    }
+
+    fn sign_value(self) -> Self {
+        if self > 0.0 {
+            1.0
+        } else if self < 0.0 {
+            -1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn powf_value(self, exponent: Self) -> Self {
+        self.powf(exponent)
+    }
+
+    fn sqrt_value(self) -> Option<Self> {
+        if self < 0.0 {
+            None
+        } else {
+            Some(self.sqrt())
+        }
+    }
+
+    fn cbrt_value(self) -> Self {
+        self.cbrt()
+    }
+
+    fn ln_value(self) -> Option<Self> {
+        if self <= 0.0 {
+            None
+        } else {
+            Some(self.ln())
+        }
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl NumericOps for rust_decimal::Decimal {
+    fn zero() -> Self {
+        rust_decimal::Decimal::ZERO
+    }
+
+    fn one() -> Self {
+        rust_decimal::Decimal::ONE
+    }
+
+    fn from_f64(v: f64) -> Self {
+        rust_decimal::Decimal::from_f64_retain(v).unwrap_or(rust_decimal::Decimal::ZERO)
+    }
+
+    fn from_json_number(n: &serde_json::Number) -> Self {
+        n.to_string()
+            .parse::<rust_decimal::Decimal>()
+            .unwrap_or_else(|_| Self::from_f64(n.as_f64().unwrap_or(0.0)))
+    }
+
+    fn pow10(decimals: i32) -> Self {
+        let ten = rust_decimal::Decimal::from(10u32);
+        let mut result = rust_decimal::Decimal::ONE;
+        if decimals >= 0 {
+            for _ in 0..decimals {
+                result *= ten;
+            }
+        } else {
+            for _ in 0..(-decimals) {
+                result /= ten;
+            }
+        }
+        result
+    }
+
+    fn display(&self) -> String {
+        self.to_string()
+    }
+
+    fn round_with_strategy(self, decimals: i32, strategy: RoundingStrategy) -> Self {
+        use rust_decimal::RoundingStrategy as DecimalStrategy;
+        let decimal_strategy = match strategy {
+            RoundingStrategy::MidpointNearestEven => DecimalStrategy::MidpointNearestEven,
+            RoundingStrategy::MidpointAwayFromZero => DecimalStrategy::MidpointAwayFromZero,
+            RoundingStrategy::ToZero => DecimalStrategy::ToZero,
+            RoundingStrategy::AwayFromZero => DecimalStrategy::AwayFromZero,
+            RoundingStrategy::ToNegativeInfinity => DecimalStrategy::ToNegativeInfinity,
+            RoundingStrategy::ToPositiveInfinity => DecimalStrategy::ToPositiveInfinity,
+        };
+        if decimals >= 0 {
+            self.round_dp_with_strategy(decimals as u32, decimal_strategy)
+        } else {
+            // `round_dp_with_strategy` only rounds to the right of the decimal
+            // point. Mirror the f64 backend's multiplier approach for negative
+            // `decimals` (rounding to tens/hundreds/etc): scale down, round to
+            // a whole number, then scale back up.
+            let multiplier = Self::pow10(-decimals);
+            (self / multiplier).round_dp_with_strategy(0, decimal_strategy) * multiplier
+        }
+    }
+
+    fn to_i32(self) -> i32 {
+        use rust_decimal::prelude::ToPrimitive;
+        ToPrimitive::to_i32(&self).unwrap_or(0)
+    }
+
+    fn floor_value(self) -> Self {
+        self.round_dp_with_strategy(0, rust_decimal::RoundingStrategy::ToNegativeInfinity)
+    }
+
+    fn trunc_value(self) -> Self {
+        self.round_dp_with_strategy(0, rust_decimal::RoundingStrategy::ToZero)
+    }
+
+    fn is_infinite_value(&self) -> bool {
+        false
+    }
+
+    fn is_finite_value(&self) -> bool {
+        true
+    }
+
+    fn ceil_value(self) -> Self {
+        self.round_dp_with_strategy(0, rust_decimal::RoundingStrategy::ToPositiveInfinity)
+    }
+
+    fn abs_value(self) -> Self {
+        self.abs()
+    }
+
+    fn sign_value(self) -> Self {
+        if self.is_zero() {
+            rust_decimal::Decimal::ZERO
+        } else if self.is_sign_negative() {
+            -rust_decimal::Decimal::ONE
+        } else {
+            rust_decimal::Decimal::ONE
+        }
+    }
+
+    fn powf_value(self, exponent: Self) -> Self {
+        use rust_decimal::MathematicalOps;
+        self.powd(exponent)
+    }
+
+    fn sqrt_value(self) -> Option<Self> {
+        use rust_decimal::MathematicalOps;
+        self.sqrt()
+    }
+
+    fn cbrt_value(self) -> Self {
+        use rust_decimal::MathematicalOps;
+        let one_third = rust_decimal::Decimal::ONE / rust_decimal::Decimal::from(3u32);
+        self.powd(one_third)
+    }
+
+    fn ln_value(self) -> Option<Self> {
+        use rust_decimal::MathematicalOps;
+        if self.is_sign_negative() || self.is_zero() {
+            None
+        } else {
+            Some(self.ln())
+        }
+    }
+}
+
 /// Pricing node configuration used by the pricing engine
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PricingNode {
@@ -14,6 +356,56 @@ pub struct PricingNode {
     pub cost: Value,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<serde_json::Value>, // None for numeric, Some(value) for label
+    /// ISO 4217-style currency code (e.g. "USD") this node's cost is denominated in.
+    /// `None` means the cost is currency-agnostic and combines freely with any currency.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+/// A numeric value tagged with an optional currency code.
+///
+/// `currency: None` marks a currency-agnostic value - a literal constant, a
+/// percentage, or a quantity - that combines freely with amounts in any
+/// currency. Amounts sourced from a [`PricingNode`] with a declared currency
+/// carry `Some(code)`, and arithmetic-combining steps refuse to mix two
+/// different declared currencies unless an explicit `convert` step precedes them.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Amount {
+    pub value: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
+}
+
+impl Amount {
+    /// A currency-agnostic amount, e.g. a literal constant.
+    pub fn bare(value: Value) -> Self {
+        Amount {
+            value,
+            currency: None,
+        }
+    }
+}
+
+/// Check that `amounts` don't mix two different declared currencies, returning
+/// the common currency, if any. Currency-agnostic (`None`) entries combine
+/// freely with a declared currency.
+fn combined_currency(amounts: &[Amount], step_name: &str) -> Result<Option<String>, String> {
+    let mut result: Option<&str> = None;
+    for amount in amounts {
+        if let Some(currency) = amount.currency.as_deref() {
+            match result {
+                None => result = Some(currency),
+                Some(existing) if existing != currency => {
+                    return Err(format!(
+                        "{}: cannot combine amounts in different currencies ({} and {})",
+                        step_name, existing, currency
+                    ))
+                }
+                _ => {}
+            }
+        }
+    }
+    Ok(result.map(|c| c.to_string()))
 }
 
 /// User input for pricing calculation
@@ -23,12 +415,35 @@ pub struct Input {
     pub value: serde_json::Value,
 }
 
-/// Condition specification for conditional steps
+/// Condition specification for conditional steps.
+///
+/// A leaf is a single comparison (`left`/`operator`/`right`, operators `>`, `<`,
+/// `>=`, `<=`, `==`, `!=`). Leaves compose via `and`/`or`/`not` into arbitrary
+/// trees, plus a `between` shorthand for a range check, so that rules like
+/// "quantity is between 10 and 50" or "A > B AND C <= D" need one `if` step
+/// instead of a chain of nested ones. Variants are tried in this order since
+/// the representation is untagged; `Leaf` is last because it's the catch-all
+/// shape.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum Condition {
+    And { and: Vec<Condition> },
+    Or { or: Vec<Condition> },
+    Not { not: Box<Condition> },
+    Between { between: BetweenRange },
+    Leaf {
+        left: serde_json::Value,
+        operator: String,
+        right: serde_json::Value,
+    },
+}
+
+/// Arguments for `Condition::Between`: `low <= value <= high`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct Condition {
-    pub left: serde_json::Value,
-    pub operator: String,
-    pub right: serde_json::Value,
+pub struct BetweenRange {
+    pub value: serde_json::Value,
+    pub low: serde_json::Value,
+    pub high: serde_json::Value,
 }
 
 /// Step configuration for pricing strategy
@@ -94,6 +509,8 @@ pub enum Step {
         inputs: Vec<serde_json::Value>,
         #[serde(skip_serializing_if = "Option::is_none")]
         decimals: Option<i32>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        strategy: Option<RoundingStrategy>,
     },
     #[serde(rename = "clamp")]
     Clamp {
@@ -114,6 +531,94 @@ pub enum Step {
         #[serde(rename = "else", skip_serializing_if = "Option::is_none")]
         else_: Option<serde_json::Value>,
     },
+    #[serde(rename = "modulo")]
+    Modulo {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        inputs: Vec<serde_json::Value>,
+    },
+    #[serde(rename = "remainder")]
+    Remainder {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        inputs: Vec<serde_json::Value>,
+    },
+    #[serde(rename = "pow")]
+    Pow {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        base: serde_json::Value,
+        exponent: serde_json::Value,
+    },
+    #[serde(rename = "sqrt")]
+    Sqrt {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        value: serde_json::Value,
+    },
+    #[serde(rename = "cbrt")]
+    Cbrt {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        value: serde_json::Value,
+    },
+    #[serde(rename = "log")]
+    Log {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        value: serde_json::Value,
+        base: serde_json::Value,
+    },
+    #[serde(rename = "floor")]
+    Floor {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        value: serde_json::Value,
+    },
+    #[serde(rename = "ceil")]
+    Ceil {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        value: serde_json::Value,
+    },
+    #[serde(rename = "trunc")]
+    Trunc {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        value: serde_json::Value,
+    },
+    #[serde(rename = "abs")]
+    Abs {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        value: serde_json::Value,
+    },
+    #[serde(rename = "sign")]
+    Sign {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        value: serde_json::Value,
+    },
+    #[serde(rename = "convert")]
+    Convert {
+        id: i32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        value: serde_json::Value,
+        to: String,
+        rate: serde_json::Value,
+    },
 }
 
 impl Step {
@@ -129,6 +634,18 @@ impl Step {
             Step::Round { id, .. } => *id,
             Step::Clamp { id, .. } => *id,
             Step::If { id, .. } => *id,
+            Step::Modulo { id, .. } => *id,
+            Step::Remainder { id, .. } => *id,
+            Step::Pow { id, .. } => *id,
+            Step::Sqrt { id, .. } => *id,
+            Step::Cbrt { id, .. } => *id,
+            Step::Log { id, .. } => *id,
+            Step::Floor { id, .. } => *id,
+            Step::Ceil { id, .. } => *id,
+            Step::Trunc { id, .. } => *id,
+            Step::Abs { id, .. } => *id,
+            Step::Sign { id, .. } => *id,
+            Step::Convert { id, .. } => *id,
         }
     }
 
@@ -144,6 +661,20 @@ impl Step {
             Step::Round { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
             Step::Clamp { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
             Step::If { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Modulo { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Remainder { id, name, .. } => {
+                name.clone().unwrap_or_else(|| format!("Step {}", id))
+            }
+            Step::Pow { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Sqrt { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Cbrt { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Log { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Floor { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Ceil { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Trunc { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Abs { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Sign { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
+            Step::Convert { id, name, .. } => name.clone().unwrap_or_else(|| format!("Step {}", id)),
         }
     }
 }
@@ -167,12 +698,18 @@ pub struct BreakdownEntry {
     pub inputs: Vec<Value>,
     pub calculation: String,
     pub result: Value,
+    /// Currency of `result`, if the step's operands carried one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub currency: Option<String>,
 }
 
 /// Result of pricing calculation
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CalculationResult {
     pub final_price: Value,
+    /// Currency of `final_price`, if the pricing strategy's steps carried one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub final_currency: Option<String>,
     pub breakdown: Vec<BreakdownEntry>,
 }
 
@@ -211,7 +748,7 @@ impl PricingEngine {
         )?;
 
         // Apply pricing strategy steps
-        let mut step_values: HashMap<i32, Value> = HashMap::new();
+        let mut step_values: HashMap<i32, Amount> = HashMap::new();
         let mut breakdown: Vec<BreakdownEntry> = Vec::new();
 
         for step in &pricing_strategy.steps {
@@ -222,14 +759,15 @@ impl PricingEngine {
         }
 
         // Get final price (last step's value)
-        let final_price = if let Some(last_step) = pricing_strategy.steps.last() {
-            *step_values.get(&last_step.id()).unwrap_or(&0.0)
+        let final_amount = if let Some(last_step) = pricing_strategy.steps.last() {
+            step_values.get(&last_step.id()).cloned().unwrap_or_else(|| Amount::bare(Value::zero()))
         } else {
-            0.0
+            Amount::bare(Value::zero())
         };
 
         Ok(CalculationResult {
-            final_price,
+            final_price: final_amount.value,
+            final_currency: final_amount.currency,
             breakdown,
         })
     }
@@ -300,8 +838,8 @@ impl PricingEngine {
         nodes_by_path: &HashMap<String, Vec<PricingNode>>,
         label_nodes: &HashMap<(String, String), PricingNode>,
         numeric_nodes: &HashMap<String, PricingNode>,
-    ) -> Result<HashMap<String, Value>, String> {
-        let mut final_cost_by_path: HashMap<String, Value> = HashMap::new();
+    ) -> Result<HashMap<String, Amount>, String> {
+        let mut final_cost_by_path: HashMap<String, Amount> = HashMap::new();
 
         for inp in inputs {
             let path = &inp.path;
@@ -327,8 +865,14 @@ impl PricingEngine {
 
             if let Some(node) = matching_node {
                 if node.node_type == "numeric" {
-                    if let Some(num) = inp.value.as_f64() {
-                        final_cost_by_path.insert(path.clone(), num * node.cost);
+                    if let serde_json::Value::Number(n) = &inp.value {
+                        final_cost_by_path.insert(
+                            path.clone(),
+                            Amount {
+                                value: Value::from_json_number(n) * node.cost,
+                                currency: node.currency.clone(),
+                            },
+                        );
                     } else {
                         return Err(format!(
                             "Invalid numeric input '{}' for path '{}'.",
@@ -336,7 +880,13 @@ impl PricingEngine {
                         ));
                     }
                 } else {
-                    final_cost_by_path.insert(path.clone(), node.cost);
+                    final_cost_by_path.insert(
+                        path.clone(),
+                        Amount {
+                            value: node.cost,
+                            currency: node.currency.clone(),
+                        },
+                    );
                 }
             } else {
                 if !nodes_by_path.contains_key(path) {
@@ -364,30 +914,37 @@ impl PricingEngine {
         Ok(final_cost_by_path)
     }
 
-    /// Resolve a value that can be a step reference, path, wildcard pattern, or literal
+    /// Resolve a value that can be a step reference, path, wildcard pattern, or literal.
+    ///
+    /// Step references and paths carry whatever currency their source amount has;
+    /// literal numbers are currency-agnostic (see [`Amount`]).
     fn resolve_value(
         &mut self,
         value: &serde_json::Value,
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<Vec<Value>, String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<Vec<Amount>, String> {
         if let Some(s) = value.as_str() {
             if s.starts_with("step__") {
                 let step_id = s[6..]
                     .parse::<i32>()
                     .map_err(|_| format!("Invalid step reference: {}", s))?;
-                Ok(vec![*step_values.get(&step_id).unwrap_or(&0.0)])
+                Ok(vec![step_values
+                    .get(&step_id)
+                    .cloned()
+                    .unwrap_or_else(|| Amount::bare(Value::zero()))])
             } else if s.contains('*') {
                 self.resolve_wildcard_pattern(s, final_cost_by_path)
             } else {
-                Ok(vec![*final_cost_by_path.get(s).unwrap_or(&0.0)])
+                Ok(vec![final_cost_by_path
+                    .get(s)
+                    .cloned()
+                    .unwrap_or_else(|| Amount::bare(Value::zero()))])
             }
-        } else if let Some(num) = value.as_f64() {
-            Ok(vec![num])
-        } else if let Some(num) = value.as_i64() {
-            Ok(vec![num as f64])
+        } else if let serde_json::Value::Number(n) = value {
+            Ok(vec![Amount::bare(Value::from_json_number(n))])
         } else {
-            Ok(vec![0.0])
+            Ok(vec![Amount::bare(Value::zero())])
         }
     }
 
@@ -407,13 +964,13 @@ impl PricingEngine {
     fn resolve_wildcard_pattern(
         &mut self,
         pattern: &str,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<Vec<Value>, String> {
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<Vec<Amount>, String> {
         let regex = self.get_regex(pattern);
-        let matching_values: Vec<Value> = final_cost_by_path
+        let matching_values: Vec<Amount> = final_cost_by_path
             .iter()
             .filter(|(path, _)| regex.is_match(path))
-            .map(|(_, &cost)| cost)
+            .map(|(_, cost)| cost.clone())
             .collect();
 
         if matching_values.is_empty() {
@@ -431,9 +988,9 @@ impl PricingEngine {
     fn process_step(
         &mut self,
         step: &Step,
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         match step {
             Step::Add { id, name, inputs } => {
                 self.process_add(*id, name.as_deref(), inputs, step_values, final_cost_by_path)
@@ -479,11 +1036,13 @@ impl PricingEngine {
                 name,
                 inputs,
                 decimals,
+                strategy,
             } => self.process_round(
                 *id,
                 name.as_deref(),
                 inputs,
                 *decimals,
+                strategy.unwrap_or_default(),
                 step_values,
                 final_cost_by_path,
             ),
@@ -517,16 +1076,88 @@ impl PricingEngine {
                 step_values,
                 final_cost_by_path,
             ),
+            Step::Modulo { id, name, inputs } => {
+                self.process_modulo(*id, name.as_deref(), inputs, step_values, final_cost_by_path)
+            }
+            Step::Remainder { id, name, inputs } => self.process_remainder(
+                *id,
+                name.as_deref(),
+                inputs,
+                step_values,
+                final_cost_by_path,
+            ),
+            Step::Pow {
+                id,
+                name,
+                base,
+                exponent,
+            } => self.process_pow(
+                *id,
+                name.as_deref(),
+                base,
+                exponent,
+                step_values,
+                final_cost_by_path,
+            ),
+            Step::Sqrt { id, name, value } => {
+                self.process_sqrt(*id, name.as_deref(), value, step_values, final_cost_by_path)
+            }
+            Step::Cbrt { id, name, value } => {
+                self.process_cbrt(*id, name.as_deref(), value, step_values, final_cost_by_path)
+            }
+            Step::Log {
+                id,
+                name,
+                value,
+                base,
+            } => self.process_log(
+                *id,
+                name.as_deref(),
+                value,
+                base,
+                step_values,
+                final_cost_by_path,
+            ),
+            Step::Floor { id, name, value } => {
+                self.process_floor(*id, name.as_deref(), value, step_values, final_cost_by_path)
+            }
+            Step::Ceil { id, name, value } => {
+                self.process_ceil(*id, name.as_deref(), value, step_values, final_cost_by_path)
+            }
+            Step::Trunc { id, name, value } => {
+                self.process_trunc(*id, name.as_deref(), value, step_values, final_cost_by_path)
+            }
+            Step::Abs { id, name, value } => {
+                self.process_abs(*id, name.as_deref(), value, step_values, final_cost_by_path)
+            }
+            Step::Sign { id, name, value } => {
+                self.process_sign(*id, name.as_deref(), value, step_values, final_cost_by_path)
+            }
+            Step::Convert {
+                id,
+                name,
+                value,
+                to,
+                rate,
+            } => self.process_convert(
+                *id,
+                name.as_deref(),
+                value,
+                to,
+                rate,
+                step_values,
+                final_cost_by_path,
+            ),
         }
     }
 
     fn resolve_inputs(
         &mut self,
         inputs: &[serde_json::Value],
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<Vec<Value>, String> {
-        let mut resolved: Vec<Value> = Vec::new();
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<Vec<Amount>, String> {
+        let mut resolved: Vec<Amount> = Vec::new();
         for item in inputs {
             let vals = self.resolve_value(item, step_values, final_cost_by_path)?;
             resolved.extend(vals);
@@ -540,25 +1171,31 @@ impl PricingEngine {
         id: i32,
         name: Option<&str>,
         inputs: &[serde_json::Value],
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
-        let resolved_inputs = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
 
-        if resolved_inputs.is_empty() {
+        if resolved_amounts.is_empty() {
             return Err(format!("{}: add requires at least one input", step_name));
         }
 
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
+
         let result: Value = resolved_inputs.iter().sum();
         let calculation = resolved_inputs
             .iter()
-            .map(|v| format!("{:.2}", v))
+            .map(|v| v.display())
             .collect::<Vec<_>>()
             .join(" + ");
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
@@ -567,6 +1204,7 @@ impl PricingEngine {
                 inputs: resolved_inputs,
                 calculation,
                 result,
+                currency,
             },
         ))
     }
@@ -577,16 +1215,19 @@ impl PricingEngine {
         id: i32,
         name: Option<&str>,
         inputs: &[serde_json::Value],
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
-        let resolved_inputs = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
 
-        if resolved_inputs.is_empty() {
+        if resolved_amounts.is_empty() {
             return Err(format!("{}: subtract requires at least one input", step_name));
         }
 
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
+
         let mut result = resolved_inputs[0];
         for &val in &resolved_inputs[1..] {
             result -= val;
@@ -594,12 +1235,15 @@ impl PricingEngine {
 
         let calculation = resolved_inputs
             .iter()
-            .map(|v| format!("{:.2}", v))
+            .map(|v| v.display())
             .collect::<Vec<_>>()
             .join(" - ");
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
@@ -611,6 +1255,7 @@ impl PricingEngine {
                 inputs: resolved_inputs,
                 calculation,
                 result,
+                currency,
             },
         ))
     }
@@ -621,29 +1266,35 @@ impl PricingEngine {
         id: i32,
         name: Option<&str>,
         inputs: &[serde_json::Value],
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
-        let resolved_inputs = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
 
-        if resolved_inputs.is_empty() {
+        if resolved_amounts.is_empty() {
             return Err(format!("{}: multiply requires at least one input", step_name));
         }
 
-        let mut result = 1.0;
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
+
+        let mut result = Value::one();
         for &val in &resolved_inputs {
             result *= val;
         }
 
         let calculation = resolved_inputs
             .iter()
-            .map(|v| format!("{:.2}", v))
+            .map(|v| v.display())
             .collect::<Vec<_>>()
             .join(" × ");
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
@@ -652,6 +1303,7 @@ impl PricingEngine {
                 inputs: resolved_inputs,
                 calculation,
                 result,
+                currency,
             },
         ))
     }
@@ -662,19 +1314,22 @@ impl PricingEngine {
         id: i32,
         name: Option<&str>,
         inputs: &[serde_json::Value],
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
-        let resolved_inputs = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
 
-        if resolved_inputs.len() < 2 {
+        if resolved_amounts.len() < 2 {
             return Err(format!("{}: divide requires at least two inputs", step_name));
         }
 
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
+
         let mut result = resolved_inputs[0];
         for &val in &resolved_inputs[1..] {
-            if val == 0.0 {
+            if val == Value::zero() {
                 return Err(format!("{}: division by zero", step_name));
             }
             result /= val;
@@ -682,12 +1337,15 @@ impl PricingEngine {
 
         let calculation = resolved_inputs
             .iter()
-            .map(|v| format!("{:.2}", v))
+            .map(|v| v.display())
             .collect::<Vec<_>>()
             .join(" ÷ ");
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
@@ -700,6 +1358,7 @@ impl PricingEngine {
                 inputs: resolved_inputs,
                 calculation,
                 result,
+                currency,
             },
         ))
     }
@@ -710,31 +1369,39 @@ impl PricingEngine {
         id: i32,
         name: Option<&str>,
         inputs: &[serde_json::Value],
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
-        let resolved_inputs = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
 
-        if resolved_inputs.is_empty() {
+        if resolved_amounts.is_empty() {
             return Err(format!("{}: min requires at least one input", step_name));
         }
 
-        let result = resolved_inputs
-            .iter()
-            .cloned()
-            .fold(f64::INFINITY, f64::min);
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
+
+        let mut result = resolved_inputs[0];
+        for &val in &resolved_inputs[1..] {
+            if val < result {
+                result = val;
+            }
+        }
         let calculation = format!(
             "min({})",
             resolved_inputs
                 .iter()
-                .map(|v| format!("{:.2}", v))
+                .map(|v| v.display())
                 .collect::<Vec<_>>()
                 .join(", ")
         );
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
@@ -743,6 +1410,7 @@ impl PricingEngine {
                 inputs: resolved_inputs,
                 calculation,
                 result,
+                currency,
             },
         ))
     }
@@ -753,31 +1421,39 @@ impl PricingEngine {
         id: i32,
         name: Option<&str>,
         inputs: &[serde_json::Value],
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
-        let resolved_inputs = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
 
-        if resolved_inputs.is_empty() {
+        if resolved_amounts.is_empty() {
             return Err(format!("{}: max requires at least one input", step_name));
         }
 
-        let result = resolved_inputs
-            .iter()
-            .cloned()
-            .fold(f64::NEG_INFINITY, f64::max);
-        let calculation = format!(
-            "max({})",
-            resolved_inputs
-                .iter()
-                .map(|v| format!("{:.2}", v))
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
+
+        let mut result = resolved_inputs[0];
+        for &val in &resolved_inputs[1..] {
+            if val > result {
+                result = val;
+            }
+        }
+        let calculation = format!(
+            "max({})",
+            resolved_inputs
+                .iter()
+                .map(|v| v.display())
                 .collect::<Vec<_>>()
                 .join(", ")
         );
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
@@ -786,6 +1462,7 @@ impl PricingEngine {
                 inputs: resolved_inputs,
                 calculation,
                 result,
+                currency,
             },
         ))
     }
@@ -797,21 +1474,21 @@ impl PricingEngine {
         name: Option<&str>,
         inputs: &[serde_json::Value],
         percent: Option<Value>,
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
-        let resolved_inputs = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
 
-        let calc_percent = if resolved_inputs.len() == 1 {
+        let calc_percent = if resolved_amounts.len() == 1 {
             percent.ok_or_else(|| {
                 format!(
                     "{}: percentage requires percent in step or two inputs",
                     step_name
                 )
             })?
-        } else if resolved_inputs.len() == 2 {
-            resolved_inputs[1]
+        } else if resolved_amounts.len() == 2 {
+            resolved_amounts[1].value
         } else {
             return Err(format!(
                 "{}: percentage allows only one or two inputs",
@@ -819,18 +1496,24 @@ impl PricingEngine {
             ));
         };
 
-        if calc_percent < 0.0 {
+        if calc_percent < Value::zero() {
             return Err(format!(
                 "{}: percentage cannot be negative ({})",
                 step_name, calc_percent
             ));
         }
 
-        let result = (resolved_inputs[0] * calc_percent) / 100.0;
-        let calculation = format!("{:.2} × {}%", resolved_inputs[0], calc_percent);
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
+
+        let result = (resolved_inputs[0] * calc_percent) / Value::from_f64(100.0);
+        let calculation = format!("{} × {}%", resolved_inputs[0].display(), calc_percent);
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
@@ -839,6 +1522,7 @@ impl PricingEngine {
                 inputs: resolved_inputs,
                 calculation,
                 result,
+                currency,
             },
         ))
     }
@@ -850,11 +1534,15 @@ impl PricingEngine {
         name: Option<&str>,
         inputs: &[serde_json::Value],
         decimals: Option<i32>,
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        strategy: RoundingStrategy,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
-        let resolved_inputs = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
 
         let (value, dec) = if resolved_inputs.len() == 1 {
             let d = decimals.ok_or_else(|| {
@@ -865,7 +1553,7 @@ impl PricingEngine {
             })?;
             (resolved_inputs[0], d)
         } else if resolved_inputs.len() == 2 {
-            (resolved_inputs[0], resolved_inputs[1] as i32)
+            (resolved_inputs[0], resolved_inputs[1].to_i32())
         } else {
             return Err(format!(
                 "{}: round allows only one or two inputs",
@@ -873,20 +1561,33 @@ impl PricingEngine {
             ));
         };
 
-        let multiplier = 10_f64.powi(dec);
-        let result = (value * multiplier).round() / multiplier;
-        let calculation = format!("round({}, {})", value, dec);
+        let result = value.round_with_strategy(dec, strategy);
+        let calculation = format!(
+            "round({}, {}, {})",
+            value.display(),
+            dec,
+            strategy.name()
+        );
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
-                operation: "Round".to_string(),
-                description: format!("Round {} to {} decimal places", value, dec),
+                operation: format!("Round ({})", strategy.name()),
+                description: format!(
+                    "Round {} to {} decimal places using {}",
+                    value.display(),
+                    dec,
+                    strategy.name()
+                ),
                 inputs: resolved_inputs,
                 calculation,
                 result,
+                currency,
             },
         ))
     }
@@ -899,14 +1600,19 @@ impl PricingEngine {
         value: &serde_json::Value,
         min: &serde_json::Value,
         max: &serde_json::Value,
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
 
-        let val = self.resolve_value(value, step_values, final_cost_by_path)?[0];
-        let min_val = self.resolve_value(min, step_values, final_cost_by_path)?[0];
-        let max_val = self.resolve_value(max, step_values, final_cost_by_path)?[0];
+        let val_amount = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+        let min_amount = self.resolve_value(min, step_values, final_cost_by_path)?[0].clone();
+        let max_amount = self.resolve_value(max, step_values, final_cost_by_path)?[0].clone();
+
+        let currency = combined_currency(&[val_amount.clone(), min_amount.clone(), max_amount.clone()], &step_name)?;
+        let val = val_amount.value;
+        let min_val = min_amount.value;
+        let max_val = max_amount.value;
 
         if min_val > max_val {
             return Err(format!(
@@ -925,10 +1631,18 @@ impl PricingEngine {
             "not clamped".to_string()
         };
 
-        let calculation = format!("clamp({:.2}, {:.2}, {:.2})", val, min_val, max_val);
+        let calculation = format!(
+            "clamp({}, {}, {})",
+            val.display(),
+            min_val.display(),
+            max_val.display()
+        );
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
@@ -940,6 +1654,7 @@ impl PricingEngine {
                 inputs: vec![val, min_val, max_val],
                 calculation,
                 result,
+                currency,
             },
         ))
     }
@@ -952,60 +1667,633 @@ impl PricingEngine {
         condition: &Condition,
         then: &serde_json::Value,
         else_: Option<&serde_json::Value>,
-        step_values: &HashMap<i32, Value>,
-        final_cost_by_path: &HashMap<String, Value>,
-    ) -> Result<(Value, BreakdownEntry), String> {
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
         let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
 
-        let left_val = self.resolve_value(&condition.left, step_values, final_cost_by_path)?[0];
-        let right_val = self.resolve_value(&condition.right, step_values, final_cost_by_path)?[0];
-
-        let condition_result = match condition.operator.as_str() {
-            ">" => left_val > right_val,
-            "<" => left_val < right_val,
-            ">=" => left_val >= right_val,
-            "<=" => left_val <= right_val,
-            "==" => left_val == right_val,
-            "!=" => left_val != right_val,
-            _ => {
-                return Err(format!(
-                    "{}: unsupported operator '{}'",
-                    step_name, condition.operator
-                ))
-            }
-        };
+        let (condition_result, condition_calc) =
+            self.evaluate_condition(condition, &step_name, step_values, final_cost_by_path)?;
 
-        let then_val = self.resolve_value(then, step_values, final_cost_by_path)?[0];
-        let else_val = if let Some(e) = else_ {
-            self.resolve_value(e, step_values, final_cost_by_path)?[0]
+        let then_amount = self.resolve_value(then, step_values, final_cost_by_path)?[0].clone();
+        let else_amount = if let Some(e) = else_ {
+            self.resolve_value(e, step_values, final_cost_by_path)?[0].clone()
         } else {
-            0.0
+            Amount::bare(Value::zero())
         };
 
-        let result = if condition_result { then_val } else { else_val };
+        let result_amount = if condition_result {
+            then_amount.clone()
+        } else {
+            else_amount.clone()
+        };
+        let currency = combined_currency(&[then_amount.clone(), else_amount.clone()], &step_name)?;
+        let then_val = then_amount.value;
+        let else_val = else_amount.value;
+        let result = result_amount.value;
 
         let calculation = format!(
-            "{:.2} {} {:.2} → {} → {:.2}",
-            left_val,
-            condition.operator,
-            right_val,
+            "{} → {} → {}",
+            condition_calc,
             if condition_result { "TRUE" } else { "FALSE" },
-            result
+            result.display()
         );
 
         Ok((
-            result,
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
             BreakdownEntry {
                 step_id: id,
                 name: step_name.to_string(),
                 operation: "Conditional".to_string(),
                 description: format!(
-                    "If {} {} {} then {} else {}",
-                    left_val, condition.operator, right_val, then_val, else_val
+                    "If {} then {} else {}",
+                    condition_calc, then_val, else_val
                 ),
-                inputs: vec![left_val, right_val, then_val, else_val],
+                inputs: vec![then_val, else_val],
+                calculation,
+                result,
+                currency,
+            },
+        ))
+    }
+
+    /// Evaluate a (possibly compound) `Condition` with short-circuit semantics,
+    /// returning the boolean result and a human-readable fragment of the
+    /// derivation (e.g. `"(5.00 >= 1.00) AND (5.00 <= 10.00)"`). Nested
+    /// sub-conditions are wrapped in parentheses; a top-level leaf is not.
+    fn evaluate_condition(
+        &mut self,
+        condition: &Condition,
+        step_name: &str,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(bool, String), String> {
+        match condition {
+            Condition::Leaf {
+                left,
+                operator,
+                right,
+            } => {
+                let left_amount = self.resolve_value(left, step_values, final_cost_by_path)?[0].clone();
+                let right_amount = self.resolve_value(right, step_values, final_cost_by_path)?[0].clone();
+                combined_currency(&[left_amount.clone(), right_amount.clone()], step_name)?;
+                let left_val = left_amount.value;
+                let right_val = right_amount.value;
+
+                let result = match operator.as_str() {
+                    ">" => left_val > right_val,
+                    "<" => left_val < right_val,
+                    ">=" => left_val >= right_val,
+                    "<=" => left_val <= right_val,
+                    "==" => left_val == right_val,
+                    "!=" => left_val != right_val,
+                    _ => {
+                        return Err(format!(
+                            "{}: unsupported operator '{}'",
+                            step_name, operator
+                        ))
+                    }
+                };
+
+                Ok((
+                    result,
+                    format!("{} {} {}", left_val.display(), operator, right_val.display()),
+                ))
+            }
+            Condition::Between { between } => {
+                let value_amount = self.resolve_value(&between.value, step_values, final_cost_by_path)?[0].clone();
+                let low_amount = self.resolve_value(&between.low, step_values, final_cost_by_path)?[0].clone();
+                let high_amount = self.resolve_value(&between.high, step_values, final_cost_by_path)?[0].clone();
+                combined_currency(
+                    &[value_amount.clone(), low_amount.clone(), high_amount.clone()],
+                    step_name,
+                )?;
+                let value = value_amount.value;
+                let low = low_amount.value;
+                let high = high_amount.value;
+
+                let result = value >= low && value <= high;
+                Ok((
+                    result,
+                    format!(
+                        "{} BETWEEN {} AND {}",
+                        value.display(),
+                        low.display(),
+                        high.display()
+                    ),
+                ))
+            }
+            Condition::Not { not } => {
+                let (sub_result, sub_calc) =
+                    self.evaluate_condition(not, step_name, step_values, final_cost_by_path)?;
+                Ok((!sub_result, format!("NOT ({})", sub_calc)))
+            }
+            Condition::And { and } => {
+                if and.is_empty() {
+                    return Err(format!("{}: 'and' requires at least one condition", step_name));
+                }
+                let mut result = true;
+                let mut fragments = Vec::with_capacity(and.len());
+                for sub in and {
+                    let (sub_result, sub_calc) =
+                        self.evaluate_condition(sub, step_name, step_values, final_cost_by_path)?;
+                    fragments.push(format!("({})", sub_calc));
+                    if !sub_result {
+                        result = false;
+                        break;
+                    }
+                }
+                Ok((result, fragments.join(" AND ")))
+            }
+            Condition::Or { or } => {
+                if or.is_empty() {
+                    return Err(format!("{}: 'or' requires at least one condition", step_name));
+                }
+                let mut result = false;
+                let mut fragments = Vec::with_capacity(or.len());
+                for sub in or {
+                    let (sub_result, sub_calc) =
+                        self.evaluate_condition(sub, step_name, step_values, final_cost_by_path)?;
+                    fragments.push(format!("({})", sub_calc));
+                    if sub_result {
+                        result = true;
+                        break;
+                    }
+                }
+                Ok((result, fragments.join(" OR ")))
+            }
+        }
+    }
+
+    /// Process modulo operation: `mod(A, B) = A - B * floor(A / B)`, taking the sign of the divisor
+    fn process_modulo(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        inputs: &[serde_json::Value],
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+
+        if resolved_amounts.len() != 2 {
+            return Err(format!(
+                "{}: modulo requires exactly two inputs (dividend, divisor)",
+                step_name
+            ));
+        }
+
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
+
+        let dividend = resolved_inputs[0];
+        let divisor = resolved_inputs[1];
+
+        if divisor == Value::zero() {
+            return Err(format!("{}: divide by zero", step_name));
+        }
+
+        let result = if divisor.is_infinite_value() {
+            if dividend != Value::zero() && (dividend < Value::zero()) != (divisor < Value::zero()) {
+                return Err(format!(
+                    "{}: mod({}, {}) is undefined (NaN) - dividend and an infinite divisor have opposite signs",
+                    step_name,
+                    dividend.display(),
+                    divisor.display()
+                ));
+            }
+            // Matching (or zero) signs: mod(A, ±∞) = A, since floor(A / ±∞) is 0.
+            // Computing that via `divisor * floor(...)` would multiply infinity by
+            // zero, which is NaN, so return the dividend directly instead.
+            dividend
+        } else {
+            dividend - divisor * (dividend / divisor).floor_value()
+        };
+        let calculation = format!(
+            "mod({}, {}) = {}",
+            dividend.display(),
+            divisor.display(),
+            result.display()
+        );
+
+        Ok((
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
+            BreakdownEntry {
+                step_id: id,
+                name: step_name.to_string(),
+                operation: "Modulo".to_string(),
+                description: format!(
+                    "{} mod {} (result takes the sign of the divisor)",
+                    dividend.display(),
+                    divisor.display()
+                ),
+                inputs: resolved_inputs,
+                calculation,
+                result,
+                currency,
+            },
+        ))
+    }
+
+    /// Process remainder operation: `rem(A, B) = A - B * trunc(A / B)`, taking the sign of the dividend
+    fn process_remainder(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        inputs: &[serde_json::Value],
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+        let resolved_amounts = self.resolve_inputs(inputs, step_values, final_cost_by_path)?;
+
+        if resolved_amounts.len() != 2 {
+            return Err(format!(
+                "{}: remainder requires exactly two inputs (dividend, divisor)",
+                step_name
+            ));
+        }
+
+        let currency = combined_currency(&resolved_amounts, &step_name)?;
+        let resolved_inputs: Vec<Value> = resolved_amounts.iter().map(|a| a.value).collect();
+
+        let dividend = resolved_inputs[0];
+        let divisor = resolved_inputs[1];
+
+        if divisor == Value::zero() {
+            return Err(format!("{}: divide by zero", step_name));
+        }
+
+        let result = dividend - divisor * (dividend / divisor).trunc_value();
+        let calculation = format!(
+            "rem({}, {}) = {}",
+            dividend.display(),
+            divisor.display(),
+            result.display()
+        );
+
+        Ok((
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
+            BreakdownEntry {
+                step_id: id,
+                name: step_name.to_string(),
+                operation: "Remainder".to_string(),
+                description: format!(
+                    "{} rem {} (result takes the sign of the dividend)",
+                    dividend.display(),
+                    divisor.display()
+                ),
+                inputs: resolved_inputs,
+                calculation,
+                result,
+                currency,
+            },
+        ))
+    }
+
+    /// Build the `(Amount, BreakdownEntry)` pair shared by the unary math steps
+    /// (sqrt, cbrt, floor, ceil, trunc, abs, sign) from the operand and its computed `result`.
+    /// The result carries the same currency as `val`, since a single operand never
+    /// needs currency reconciliation.
+    fn unary_math_entry(
+        id: i32,
+        step_name: &str,
+        func_name: &str,
+        operation: &str,
+        val: Amount,
+        result: Value,
+    ) -> (Amount, BreakdownEntry) {
+        let calculation = format!("{}({}) = {}", func_name, val.value.display(), result.display());
+        let currency = val.currency.clone();
+        (
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
+            BreakdownEntry {
+                step_id: id,
+                name: step_name.to_string(),
+                operation: operation.to_string(),
+                description: format!("{} of {}", operation, val.value.display()),
+                inputs: vec![val.value],
+                calculation,
+                result,
+                currency,
+            },
+        )
+    }
+
+    /// Process exponentiation operation: `pow(base, exponent)`
+    fn process_pow(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        base: &serde_json::Value,
+        exponent: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+
+        let base_amount = self.resolve_value(base, step_values, final_cost_by_path)?[0].clone();
+        let exponent_amount = self.resolve_value(exponent, step_values, final_cost_by_path)?[0].clone();
+        let currency = combined_currency(&[base_amount.clone(), exponent_amount.clone()], &step_name)?;
+        let base_val = base_amount.value;
+        let exponent_val = exponent_amount.value;
+
+        if base_val < Value::zero() && exponent_val.trunc_value() != exponent_val {
+            return Err(format!(
+                "{}: pow({}, {}) is undefined - a negative base requires an integer exponent",
+                step_name,
+                base_val.display(),
+                exponent_val.display()
+            ));
+        }
+
+        let result = base_val.powf_value(exponent_val);
+        if !result.is_finite_value() {
+            return Err(format!(
+                "{}: pow({}, {}) overflowed to a non-finite result",
+                step_name,
+                base_val.display(),
+                exponent_val.display()
+            ));
+        }
+
+        let calculation = format!(
+            "pow({}, {}) = {}",
+            base_val.display(),
+            exponent_val.display(),
+            result.display()
+        );
+
+        Ok((
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
+            BreakdownEntry {
+                step_id: id,
+                name: step_name.to_string(),
+                operation: "Power".to_string(),
+                description: format!("{} raised to the power of {}", base_val.display(), exponent_val.display()),
+                inputs: vec![base_val, exponent_val],
+                calculation,
+                result,
+                currency,
+            },
+        ))
+    }
+
+    /// Process square root operation
+    fn process_sqrt(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        value: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+        let val = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+
+        let result = val.value.sqrt_value().ok_or_else(|| {
+            format!(
+                "{}: sqrt is undefined for negative input ({})",
+                step_name,
+                val.value.display()
+            )
+        })?;
+
+        Ok(Self::unary_math_entry(id, &step_name, "sqrt", "Square Root", val, result))
+    }
+
+    /// Process cube root operation
+    fn process_cbrt(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        value: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+        let val = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+        let result = val.value.cbrt_value();
+
+        Ok(Self::unary_math_entry(id, &step_name, "cbrt", "Cube Root", val, result))
+    }
+
+    /// Process logarithm operation: `log(value, base)`
+    fn process_log(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        value: &serde_json::Value,
+        base: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+
+        let val_amount = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+        let base_amount = self.resolve_value(base, step_values, final_cost_by_path)?[0].clone();
+        let currency = combined_currency(&[val_amount.clone(), base_amount.clone()], &step_name)?;
+        let val = val_amount.value;
+        let base_val = base_amount.value;
+
+        let ln_val = val.ln_value().ok_or_else(|| {
+            format!(
+                "{}: log is undefined for non-positive input ({})",
+                step_name,
+                val.display()
+            )
+        })?;
+        let ln_base = base_val.ln_value().ok_or_else(|| {
+            format!(
+                "{}: log requires a positive base, not {}",
+                step_name,
+                base_val.display()
+            )
+        })?;
+        if ln_base == Value::zero() {
+            return Err(format!(
+                "{}: log requires a base other than 1 ({})",
+                step_name,
+                base_val.display()
+            ));
+        }
+
+        let result = ln_val / ln_base;
+        let calculation = format!(
+            "log({}, {}) = {}",
+            val.display(),
+            base_val.display(),
+            result.display()
+        );
+
+        Ok((
+            Amount {
+                value: result,
+                currency: currency.clone(),
+            },
+            BreakdownEntry {
+                step_id: id,
+                name: step_name.to_string(),
+                operation: "Logarithm".to_string(),
+                description: format!("log base {} of {}", base_val.display(), val.display()),
+                inputs: vec![val, base_val],
+                calculation,
+                result,
+                currency,
+            },
+        ))
+    }
+
+    /// Process floor operation
+    fn process_floor(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        value: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+        let val = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+        let result = val.value.floor_value();
+
+        Ok(Self::unary_math_entry(id, &step_name, "floor", "Floor", val, result))
+    }
+
+    /// Process ceiling operation
+    fn process_ceil(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        value: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+        let val = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+        let result = val.value.ceil_value();
+
+        Ok(Self::unary_math_entry(id, &step_name, "ceil", "Ceiling", val, result))
+    }
+
+    /// Process truncation operation
+    fn process_trunc(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        value: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+        let val = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+        let result = val.value.trunc_value();
+
+        Ok(Self::unary_math_entry(id, &step_name, "trunc", "Truncate", val, result))
+    }
+
+    /// Process absolute value operation
+    fn process_abs(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        value: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+        let val = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+        let result = val.value.abs_value();
+
+        Ok(Self::unary_math_entry(id, &step_name, "abs", "Absolute Value", val, result))
+    }
+
+    /// Process sign operation, returning -1, 0, or 1
+    fn process_sign(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        value: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+        let val = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+        let result = val.value.sign_value();
+
+        Ok(Self::unary_math_entry(id, &step_name, "sign", "Sign", val, result))
+    }
+
+    /// Process currency conversion: `convert(value, rate) -> value × rate`, tagged with
+    /// the target currency. `rate` is the target-per-source exchange rate, supplied inline
+    /// on the step rather than via an engine-level rate table.
+    fn process_convert(
+        &mut self,
+        id: i32,
+        name: Option<&str>,
+        value: &serde_json::Value,
+        to: &str,
+        rate: &serde_json::Value,
+        step_values: &HashMap<i32, Amount>,
+        final_cost_by_path: &HashMap<String, Amount>,
+    ) -> Result<(Amount, BreakdownEntry), String> {
+        let step_name = name.map(|s| s.to_string()).unwrap_or_else(|| format!("Step {}", id));
+
+        let val = self.resolve_value(value, step_values, final_cost_by_path)?[0].clone();
+        let rate_val = self.resolve_value(rate, step_values, final_cost_by_path)?[0].clone();
+
+        if rate_val.value <= Value::zero() {
+            return Err(format!(
+                "{}: convert requires a positive exchange rate ({})",
+                step_name,
+                rate_val.value.display()
+            ));
+        }
+
+        let result = val.value * rate_val.value;
+        let from_label = val
+            .currency
+            .clone()
+            .unwrap_or_else(|| "(no currency)".to_string());
+        let calculation = format!(
+            "{} {} × {} = {} {}",
+            val.value.display(),
+            from_label,
+            rate_val.value.display(),
+            result.display(),
+            to
+        );
+
+        Ok((
+            Amount {
+                value: result,
+                currency: Some(to.to_string()),
+            },
+            BreakdownEntry {
+                step_id: id,
+                name: step_name.to_string(),
+                operation: "Convert".to_string(),
+                description: format!("Convert {} {} to {}", val.value.display(), from_label, to),
+                inputs: vec![val.value, rate_val.value],
                 calculation,
                 result,
+                currency: Some(to.to_string()),
             },
         ))
     }